@@ -13,14 +13,45 @@
 //! # References
 //! - \[1\] [Mark E. Glickman: The Glicko system.](http://www.glicko.net/glicko/glicko.pdf)
 //! - \[2\] [Mark E. Glickman: Parameter estimation in large dynamic paired comparison experiments.](http://www.glicko.net/research/glicko.pdf)
+//! - \[3\] [Mark E. Glickman: Example of the Glicko-2 system.](http://www.glicko.net/glicko/glicko2.pdf)
+//! - \[4\] [Mark E. Glickman: A comprehensive guide to chess ratings.](http://www.glicko.net/research/acjpaper.pdf) (Glicko-Boost, as used for the FIDE/Deloitte Man vs Machine challenge)
 
+use std::cmp::Ordering;
 use std::f64::consts::PI;
 
-/// A player with a rating and a rating deviation.
+/// Tunable constants underlying the rating system. See [1].
+#[derive(Debug, Clone, Copy, PartialEq)]
+struct GlickoConfig {
+    /// The rating assigned to an unrated player (1500 in the original system).
+    default_rating: f64,
+    /// The RD assigned to an unrated player, and the RD ceiling (350 in the
+    /// original system).
+    default_rd: f64,
+    /// The scale factor of the logistic curve (400 in the original system).
+    scale: f64,
+    /// The base of the logistic curve (10 in the original system).
+    base: f64,
+}
+
+impl Default for GlickoConfig {
+    /// Reproduces the constants the Glicko paper [1] uses.
+    fn default() -> Self {
+        Self {
+            default_rating: 1500.0,
+            default_rd: 350.0,
+            scale: 400.0,
+            base: 10.0,
+        }
+    }
+}
+
+/// A player with a rating and a rating deviation. All players in a rating
+/// period passed to `update`/`update_with_boost` must share the same `config`.
 #[derive(Clone)]
 struct Player {
     rating: f64,
     rd: f64, // rating deviation (RD)
+    config: GlickoConfig,
 }
 
 /// An outcome of a game consists of a loss, a win, and a draw.
@@ -32,8 +63,18 @@ enum Outcome {
     Draw,
 }
 
-/// An opponent and a their match result.
-struct GameResult(Player, Outcome);
+/// Which side of a game held the first-move/first-mover advantage, for use
+/// with `GlickoBoostConfig` (e.g. playing White in chess).
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[allow(unused)]
+enum Side {
+    Advantaged,
+    Disadvantaged,
+}
+
+/// An opponent, a their match result, and an optional side indicator used to
+/// apply the `GlickoBoostConfig` advantage parameter to this particular game.
+struct GameResult(Player, Outcome, Option<Side>);
 
 impl GameResult {
     // Only used in the update function
@@ -46,43 +87,166 @@ impl GameResult {
     }
 }
 
+/// Configuration for the Glicko-Boost extensions Glickman introduced for the
+/// FIDE/Deloitte "Man vs Machine" chess challenge: a first-move advantage and
+/// a rating-deviation boost for players whose observed score greatly exceeds
+/// their expected score. See [4].
+#[derive(Debug, Clone, Copy)]
+#[allow(unused)]
+struct GlickoBoostConfig {
+    /// η: rating points added to the advantaged side (and subtracted from the
+    /// disadvantaged side) inside the E(·) computation for a game.
+    eta: f64,
+    /// B1: the threshold that (observed score − expected score), summed over
+    /// a rating period, must exceed for a player's RD to be boosted as
+    /// "over-performing".
+    b1: f64,
+    /// B2: the factor the post-update RD is scaled by when B1 is exceeded;
+    /// the boosted RD is capped at the player's `GlickoConfig::default_rd`.
+    b2: f64,
+    /// c: the decay constant `update_with_boost` passes to `Player::onset_rd`
+    /// for a player with no games this period; see `c_from_periods_to_unrated`.
+    c: f64,
+}
+
+impl Default for GlickoBoostConfig {
+    /// η = 0, an unreachable B1 threshold, and c = 0 reproduce today's plain
+    /// `Player::update` behaviour exactly.
+    fn default() -> Self {
+        Self {
+            eta: 0.0,
+            b1: f64::INFINITY,
+            b2: 1.0,
+            c: 0.0,
+        }
+    }
+}
+
 #[allow(unused)]
 impl Player {
-    /// Generate an unrated player.
+    /// Generate an unrated player using the default `GlickoConfig`.
     #[allow(unused)]
     pub fn new() -> Self {
+        Self::with_config(GlickoConfig::default())
+    }
+
+    /// Generate an unrated player using the given `GlickoConfig`.
+    pub fn with_config(config: GlickoConfig) -> Self {
         Self {
-            rating: 1500.0,
-            rd: 350.0,
+            rating: config.default_rating,
+            rd: config.default_rd,
+            config,
         }
     }
 
     /// Update the player's rating and RD.
     pub fn update(&mut self, game_results: &Vec<GameResult>) {
+        assert!(game_results.iter().all(|o| o.0.config == self.config));
+
         // calculate 1 / d^2
-        let dd_inv = Self::q().powf(2.0)
+        let dd_inv = self.q().powf(2.0)
             * game_results
                 .iter()
-                .map(|o| o.0.g().powf(2.0) * o.0.e(self.rating) * (1.0 - o.0.e(self.rating)))
+                .map(|o| {
+                    o.0.g(&self.config).powf(2.0)
+                        * o.0.e(self.rating, &self.config)
+                        * (1.0 - o.0.e(self.rating, &self.config))
+                })
                 .sum::<f64>();
 
         let sum = game_results
             .iter()
-            .map(|o| o.0.g() * (o.to_f64() - o.0.e(self.rating)))
+            .map(|o| o.0.g(&self.config) * (o.to_f64() - o.0.e(self.rating, &self.config)))
             .sum::<f64>();
 
-        self.rating += Self::q() / (1.0 / self.rd.powf(2.0) + dd_inv) * sum;
+        self.rating += self.q() / (1.0 / self.rd.powf(2.0) + dd_inv) * sum;
         self.rd = (1.0 / (1.0 / (self.rd * self.rd) + dd_inv)).sqrt();
     }
 
+    /// Update the player's rating and RD like `update`, but apply the
+    /// Glicko-Boost extensions in `config`. See [4].
+    pub fn update_with_boost(&mut self, game_results: &Vec<GameResult>, config: &GlickoBoostConfig) {
+        assert!(game_results.iter().all(|o| o.0.config == self.config));
+
+        if game_results.is_empty() {
+            self.onset_rd(1, config.c);
+            return;
+        }
+
+        let old_rating = self.rating;
+        let advantaged_ratings: Vec<f64> = game_results
+            .iter()
+            .map(|o| match o.2 {
+                Some(Side::Advantaged) => old_rating + config.eta,
+                Some(Side::Disadvantaged) => old_rating - config.eta,
+                None => old_rating,
+            })
+            .collect();
+
+        let dd_inv = self.q().powf(2.0)
+            * game_results
+                .iter()
+                .zip(&advantaged_ratings)
+                .map(|(o, &r)| {
+                    o.0.g(&self.config).powf(2.0) * o.0.e(r, &self.config) * (1.0 - o.0.e(r, &self.config))
+                })
+                .sum::<f64>();
+
+        let sum = game_results
+            .iter()
+            .zip(&advantaged_ratings)
+            .map(|(o, &r)| o.0.g(&self.config) * (o.to_f64() - o.0.e(r, &self.config)))
+            .sum::<f64>();
+
+        self.rating += self.q() / (1.0 / self.rd.powf(2.0) + dd_inv) * sum;
+        let updated_rd = (1.0 / (1.0 / (self.rd * self.rd) + dd_inv)).sqrt();
+
+        let observed: f64 = game_results.iter().map(|o| o.to_f64()).sum();
+        let expected: f64 = game_results
+            .iter()
+            .zip(&advantaged_ratings)
+            .map(|(o, &r)| o.0.e(r, &self.config))
+            .sum();
+
+        self.rd = if observed - expected > config.b1 {
+            (updated_rd * config.b2).min(self.config.default_rd)
+        } else {
+            updated_rd
+        };
+    }
+
+    /// Increase RD for a player who did not play during `periods` consecutive
+    /// rating periods; the player's rating itself is unaffected. See [1].
+    ///
+    /// `c` is the system constant controlling how quickly an inactive
+    /// player's RD returns to the unrated ceiling; see
+    /// `Player::c_from_periods_to_unrated`.
+    pub fn onset_rd(&mut self, periods: u32, c: f64) {
+        self.rd = (self.rd.powf(2.0) + c.powf(2.0) * periods as f64)
+            .sqrt()
+            .min(self.config.default_rd);
+    }
+
+    /// Compute the system constant `c` such that a player whose RD is
+    /// `typical_rd` returns to `config`'s default/unrated RD after
+    /// `periods_to_unrated` consecutive inactive rating periods.
+    pub fn c_from_periods_to_unrated(
+        config: &GlickoConfig,
+        typical_rd: f64,
+        periods_to_unrated: f64,
+    ) -> f64 {
+        ((config.default_rd.powf(2.0) - typical_rd.powf(2.0)) / periods_to_unrated).sqrt()
+    }
+
     /// Calculate an expected outcome of a game with an opponent.
     pub fn expected_outcome(&self, opponent: &Player) -> f64 {
         let arg_g = Player {
             rating: 0.0,
             rd: (self.rd.powf(2.0) + opponent.rd.powf(2.0)).sqrt(),
+            config: self.config,
         };
-        let pow = -arg_g.g() * (self.rating - opponent.rating) / 400.0;
-        1.0 / (1.0 + 10.0_f64.powf(pow))
+        let pow = -arg_g.g(&self.config) * (self.rating - opponent.rating) / self.config.scale;
+        1.0 / (1.0 + self.config.base.powf(pow))
     }
 
     /// Get a 95 % confidence interaval for the player.
@@ -90,19 +254,205 @@ impl Player {
         (self.rating - 1.96 * self.rd, self.rating + 1.96 * self.rd)
     }
 
+    /// Rate a round of standings (place per player, lower is better) by
+    /// converting every pair into a virtual game and updating each player.
+    pub fn round_update(standings: &mut [(&mut Player, usize)]) {
+        // Snapshot the pre-round ratings so that every player's virtual
+        // games are scored against the same ratings, independent of the
+        // order in which players are updated below.
+        let snapshot: Vec<(Player, usize)> = standings
+            .iter()
+            .map(|(player, place)| ((**player).clone(), *place))
+            .collect();
+
+        for (i, (player, place)) in standings.iter_mut().enumerate() {
+            let game_results: Vec<GameResult> = snapshot
+                .iter()
+                .enumerate()
+                .filter(|(j, _)| *j != i)
+                .map(|(_, (opponent, opponent_place))| {
+                    let outcome = match (*place).cmp(opponent_place) {
+                        Ordering::Less => Outcome::Win,
+                        Ordering::Equal => Outcome::Draw,
+                        Ordering::Greater => Outcome::Lose,
+                    };
+                    GameResult(opponent.clone(), outcome, None)
+                })
+                .collect();
+
+            player.update(&game_results);
+        }
+    }
+
     // The name of variables below correspond to ``The Glicko system''
-    fn q() -> f64 {
-        10_f64.ln() / 400.0
+    fn q(&self) -> f64 {
+        self.config.base.ln() / self.config.scale
     }
 
-    fn g(&self) -> f64 {
-        let t = 1.0 + 3.0 * Self::q().powf(2.0) * self.rd.powf(2.0) / PI.powf(2.0);
+    // `config` is always the config of the player being updated, not of
+    // `self` (the opponent `g`/`e` is evaluated for); see the invariant on
+    // `Player`.
+    fn g(&self, config: &GlickoConfig) -> f64 {
+        let q = config.base.ln() / config.scale;
+        let t = 1.0 + 3.0 * q.powf(2.0) * self.rd.powf(2.0) / PI.powf(2.0);
         1.0 / t.sqrt()
     }
 
-    fn e(&self, r: f64) -> f64 {
-        let t = -self.g() * (r - self.rating) / 400.0;
-        1.0 / (1.0 + 10_f64.powf(t))
+    fn e(&self, r: f64, config: &GlickoConfig) -> f64 {
+        let t = -self.g(config) * (r - self.rating) / config.scale;
+        1.0 / (1.0 + config.base.powf(t))
+    }
+}
+
+/// Glicko-2 system constant τ that constrains the change in volatility over time.
+/// Glickman recommends a value between 0.3 and 1.2; smaller values restrict
+/// volatility (and hence rating) swings more tightly.
+const TAU: f64 = 0.5;
+
+/// A player with a rating, rating deviation, and volatility, as used by the
+/// Glicko-2 rating system (an extension of [1] described in [3]).
+#[derive(Clone)]
+struct Glicko2Player {
+    rating: f64,
+    rd: f64,
+    volatility: f64, // σ
+}
+
+/// An opponent and their match result, for use with `Glicko2Player::update`.
+struct GameResult2(Glicko2Player, Outcome);
+
+impl GameResult2 {
+    // Only used in the update function
+    fn to_f64(&self) -> f64 {
+        match self.1 {
+            Outcome::Win => 1.0,
+            Outcome::Lose => 0.0,
+            Outcome::Draw => 0.5,
+        }
+    }
+}
+
+#[allow(unused)]
+impl Glicko2Player {
+    /// Generate an unrated player.
+    pub fn new() -> Self {
+        Self {
+            rating: 1500.0,
+            rd: 350.0,
+            volatility: 0.06,
+        }
+    }
+
+    /// Update the player's rating, RD, and volatility using a rating period's results.
+    pub fn update(&mut self, game_results: &Vec<GameResult2>) {
+        if game_results.is_empty() {
+            return;
+        }
+
+        let mu = self.mu();
+        let phi = self.phi();
+
+        // (g_j, E(mu, mu_j, phi_j), s_j) for every opponent faced this period
+        let terms: Vec<(f64, f64, f64)> = game_results
+            .iter()
+            .map(|o| {
+                let mu_j = o.0.mu();
+                let phi_j = o.0.phi();
+                (Self::g(phi_j), Self::e(mu, mu_j, phi_j), o.to_f64())
+            })
+            .collect();
+
+        // v: the estimated variance of the rating based on the game outcomes
+        let v = 1.0
+            / terms
+                .iter()
+                .map(|(g_j, e_j, _)| g_j.powf(2.0) * e_j * (1.0 - e_j))
+                .sum::<f64>();
+
+        // delta: the estimated improvement in rating by comparing the
+        // pre-period rating to the performance rating based on the game outcomes
+        let delta = v
+            * terms
+                .iter()
+                .map(|(g_j, e_j, s_j)| g_j * (s_j - e_j))
+                .sum::<f64>();
+
+        let sigma_prime = self.new_volatility(delta, phi, v);
+
+        let phi_star = (phi.powf(2.0) + sigma_prime.powf(2.0)).sqrt();
+        let phi_prime = 1.0 / (1.0 / phi_star.powf(2.0) + 1.0 / v).sqrt();
+        let mu_prime = mu
+            + phi_prime.powf(2.0)
+                * terms
+                    .iter()
+                    .map(|(g_j, e_j, s_j)| g_j * (s_j - e_j))
+                    .sum::<f64>();
+
+        self.rating = 173.7178 * mu_prime + 1500.0;
+        self.rd = 173.7178 * phi_prime;
+        self.volatility = sigma_prime;
+    }
+
+    /// Calculate an expected outcome of a game with an opponent, on the 1500/350 scale.
+    pub fn expected_outcome(&self, opponent: &Glicko2Player) -> f64 {
+        Self::e(self.mu(), opponent.mu(), opponent.phi())
+    }
+
+    /// Convert the rating to the Glicko-2 internal scale, μ.
+    fn mu(&self) -> f64 {
+        (self.rating - 1500.0) / 173.7178
+    }
+
+    /// Convert the RD to the Glicko-2 internal scale, φ.
+    fn phi(&self) -> f64 {
+        self.rd / 173.7178
+    }
+
+    fn g(phi: f64) -> f64 {
+        1.0 / (1.0 + 3.0 * phi.powf(2.0) / PI.powf(2.0)).sqrt()
+    }
+
+    fn e(mu: f64, mu_j: f64, phi_j: f64) -> f64 {
+        1.0 / (1.0 + (-Self::g(phi_j) * (mu - mu_j)).exp())
+    }
+
+    /// Solve for the new volatility σ' with the Illinois algorithm (step 5 in [3]).
+    fn new_volatility(&self, delta: f64, phi: f64, v: f64) -> f64 {
+        let a = (self.volatility.powf(2.0)).ln();
+        let f = |x: f64| {
+            let ex = x.exp();
+            let numerator = ex * (delta.powf(2.0) - phi.powf(2.0) - v - ex);
+            let denominator = 2.0 * (phi.powf(2.0) + v + ex).powf(2.0);
+            numerator / denominator - (x - a) / TAU.powf(2.0)
+        };
+
+        let mut big_a = a;
+        let mut big_b = if delta.powf(2.0) > phi.powf(2.0) + v {
+            (delta.powf(2.0) - phi.powf(2.0) - v).ln()
+        } else {
+            let mut k = 1.0;
+            while f(a - k * TAU) < 0.0 {
+                k += 1.0;
+            }
+            a - k * TAU
+        };
+
+        let mut f_a = f(big_a);
+        let mut f_b = f(big_b);
+        while (big_b - big_a).abs() > 1e-6 {
+            let big_c = big_a + (big_a - big_b) * f_a / (f_b - f_a);
+            let f_c = f(big_c);
+            if f_c * f_b <= 0.0 {
+                big_a = big_b;
+                f_a = f_b;
+            } else {
+                f_a /= 2.0;
+            }
+            big_b = big_c;
+            f_b = f_c;
+        }
+
+        (big_a / 2.0).exp()
     }
 }
 
@@ -119,26 +469,30 @@ mod tests {
         let mut main_player = Player {
             rating: 1500.0,
             rd: 200.0,
+            config: GlickoConfig::default(),
         };
 
         // 3 opponets in a rating period
         let p1 = Player {
             rating: 1400.0,
             rd: 30.0,
+            config: GlickoConfig::default(),
         };
         let p2 = Player {
             rating: 1550.0,
             rd: 100.0,
+            config: GlickoConfig::default(),
         };
         let p3 = Player {
             rating: 1700.0,
             rd: 300.0,
+            config: GlickoConfig::default(),
         };
 
         let game_results = vec![
-            GameResult(p1.clone(), Outcome::Win),
-            GameResult(p2.clone(), Outcome::Lose),
-            GameResult(p3.clone(), Outcome::Lose),
+            GameResult(p1.clone(), Outcome::Win, None),
+            GameResult(p2.clone(), Outcome::Lose, None),
+            GameResult(p3.clone(), Outcome::Lose, None),
         ];
 
         main_player.update(&game_results);
@@ -159,10 +513,12 @@ mod tests {
         let p1 = Player {
             rating: 1400.0,
             rd: 80.0,
+            config: GlickoConfig::default(),
         };
         let p2 = Player {
             rating: 1500.0,
             rd: 150.0,
+            config: GlickoConfig::default(),
         };
 
         assert!((p1.expected_outcome(&p2) - 0.376).abs() < 0.001); // EO(p1, p2) ~ 0.376
@@ -180,6 +536,7 @@ mod tests {
         let p = Player {
             rating: 1500.0,
             rd: 30.0,
+            config: GlickoConfig::default(),
         };
 
         let interval = p.get_95confidence_interval();
@@ -193,6 +550,281 @@ mod tests {
             interval.0, interval.1
         );
     }
+
+    /// (4) Example of a rating update with the Glicko-2 system, taken directly
+    /// from the worked example in [3].
+    #[test]
+    fn test_update_rating_glicko2() {
+        // Player to be updated
+        let mut main_player = Glicko2Player {
+            rating: 1500.0,
+            rd: 200.0,
+            volatility: 0.06,
+        };
+
+        // 3 opponents in a rating period
+        let p1 = Glicko2Player {
+            rating: 1400.0,
+            rd: 30.0,
+            volatility: 0.06,
+        };
+        let p2 = Glicko2Player {
+            rating: 1550.0,
+            rd: 100.0,
+            volatility: 0.06,
+        };
+        let p3 = Glicko2Player {
+            rating: 1700.0,
+            rd: 300.0,
+            volatility: 0.06,
+        };
+
+        let game_results = vec![
+            GameResult2(p1.clone(), Outcome::Win),
+            GameResult2(p2.clone(), Outcome::Lose),
+            GameResult2(p3.clone(), Outcome::Lose),
+        ];
+
+        main_player.update(&game_results);
+
+        assert!((main_player.rating - 1464.06).abs() < 0.1);
+        assert!((main_player.rd - 151.52).abs() < 0.1);
+        assert!((main_player.volatility - 0.05999).abs() < 0.0001);
+
+        // If you want to check the resulting numbers, do `$ cargo test -- --nocapture`.
+        println!(
+            "rating: {:.2}, RD: {:.2}, volatility: {:.5}",
+            main_player.rating, main_player.rd, main_player.volatility
+        );
+    }
+
+    /// (5) Example of RD decay for a player who sits out several rating periods
+    #[test]
+    fn test_onset_rd() {
+        // c chosen so that a player with a typical RD of 50 becomes fully
+        // unrated (RD 350) after 30 inactive rating periods
+        let c = Player::c_from_periods_to_unrated(&GlickoConfig::default(), 50.0, 30.0);
+
+        let mut player = Player {
+            rating: 1500.0,
+            rd: 50.0,
+            config: GlickoConfig::default(),
+        };
+
+        player.onset_rd(30, c);
+        assert!((player.rd - 350.0).abs() < 1e-6);
+
+        // Staying inactive any longer cannot push RD past the 350 ceiling.
+        player.onset_rd(10, c);
+        assert!((player.rd - 350.0).abs() < 1e-6);
+
+        // If you want to check the resulting numbers, do `$ cargo test -- --nocapture`.
+        println!("c: {:.3}, RD after onset: {:.1}", c, player.rd);
+    }
+
+    /// (6) The default `GlickoBoostConfig` reproduces plain `update`
+    #[test]
+    fn test_update_with_boost_default_matches_update() {
+        let p1 = Player {
+            rating: 1400.0,
+            rd: 30.0,
+            config: GlickoConfig::default(),
+        };
+        let p2 = Player {
+            rating: 1550.0,
+            rd: 100.0,
+            config: GlickoConfig::default(),
+        };
+
+        let mut plain = Player {
+            rating: 1500.0,
+            rd: 200.0,
+            config: GlickoConfig::default(),
+        };
+        let mut boosted = plain.clone();
+
+        plain.update(&vec![
+            GameResult(p1.clone(), Outcome::Win, None),
+            GameResult(p2.clone(), Outcome::Lose, None),
+        ]);
+        boosted.update_with_boost(
+            &vec![
+                GameResult(p1.clone(), Outcome::Win, None),
+                GameResult(p2.clone(), Outcome::Lose, None),
+            ],
+            &GlickoBoostConfig::default(),
+        );
+
+        assert!((plain.rating - boosted.rating).abs() < 1e-9);
+        assert!((plain.rd - boosted.rd).abs() < 1e-9);
+    }
+
+    /// (7) A first-move advantage raises the advantaged player's expected
+    /// score, so a win moves their rating up by less
+    #[test]
+    fn test_update_with_boost_advantage() {
+        let opponent = Player {
+            rating: 1500.0,
+            rd: 100.0,
+            config: GlickoConfig::default(),
+        };
+
+        let mut neutral = Player {
+            rating: 1500.0,
+            rd: 100.0,
+            config: GlickoConfig::default(),
+        };
+        let mut advantaged = neutral.clone();
+
+        let config = GlickoBoostConfig {
+            eta: 50.0,
+            ..GlickoBoostConfig::default()
+        };
+
+        neutral.update_with_boost(
+            &vec![GameResult(opponent.clone(), Outcome::Win, None)],
+            &config,
+        );
+        advantaged.update_with_boost(
+            &vec![GameResult(
+                opponent.clone(),
+                Outcome::Win,
+                Some(Side::Advantaged),
+            )],
+            &config,
+        );
+
+        // The advantaged player was "expected" to win more, so the same win
+        // raises their rating by less than the neutral player's.
+        assert!(advantaged.rating - 1500.0 < neutral.rating - 1500.0);
+    }
+
+    /// (8) Greatly over-performing the expected score boosts the post-update RD
+    #[test]
+    fn test_update_with_boost_rd_boost() {
+        let weak_opponent = Player {
+            rating: 1000.0,
+            rd: 50.0,
+            config: GlickoConfig::default(),
+        };
+
+        let mut unboosted = Player {
+            rating: 1500.0,
+            rd: 100.0,
+            config: GlickoConfig::default(),
+        };
+        let mut boosted = unboosted.clone();
+
+        unboosted.update_with_boost(
+            &vec![GameResult(weak_opponent.clone(), Outcome::Win, None)],
+            &GlickoBoostConfig::default(),
+        );
+        boosted.update_with_boost(
+            &vec![GameResult(weak_opponent.clone(), Outcome::Win, None)],
+            &GlickoBoostConfig {
+                b1: 0.0,
+                b2: 1.5,
+                ..GlickoBoostConfig::default()
+            },
+        );
+
+        assert!((boosted.rd - unboosted.rd * 1.5).abs() < 1e-9);
+    }
+
+    /// (9) A player with no games this period has their RD decayed via c
+    #[test]
+    fn test_update_with_boost_idle_decay() {
+        let mut player = Player {
+            rating: 1500.0,
+            rd: 50.0,
+            config: GlickoConfig::default(),
+        };
+
+        let config = GlickoBoostConfig {
+            c: Player::c_from_periods_to_unrated(&GlickoConfig::default(), 50.0, 30.0),
+            ..GlickoBoostConfig::default()
+        };
+
+        player.update_with_boost(&vec![], &config);
+
+        assert!((player.rating - 1500.0).abs() < 1e-9);
+        assert!(player.rd > 50.0);
+    }
+
+    /// (10) Example of rating a full round from tournament standings
+    #[test]
+    fn test_round_update() {
+        let mut p1 = Player {
+            rating: 1500.0,
+            rd: 200.0,
+            config: GlickoConfig::default(),
+        };
+        let mut p2 = Player {
+            rating: 1500.0,
+            rd: 200.0,
+            config: GlickoConfig::default(),
+        };
+        let mut p3 = Player {
+            rating: 1500.0,
+            rd: 200.0,
+            config: GlickoConfig::default(),
+        };
+
+        let mut standings: Vec<(&mut Player, usize)> = vec![(&mut p1, 1), (&mut p2, 2), (&mut p3, 3)];
+        Player::round_update(&mut standings);
+
+        // The 1st-place finisher gained rating, the last-place finisher lost
+        // rating, and the tied-overall 2nd-place finisher (one win, one loss
+        // against equally-rated opponents) is unchanged.
+        assert!(p1.rating > 1500.0);
+        assert!((p2.rating - 1500.0).abs() < 1e-9);
+        assert!(p3.rating < 1500.0);
+        assert!(p1.rating > p2.rating && p2.rating > p3.rating);
+    }
+
+    /// (11) Example of tuning the system's constants via `GlickoConfig`
+    #[test]
+    fn test_custom_config() {
+        let config = GlickoConfig {
+            default_rating: 0.0,
+            default_rd: 50.0,
+            scale: 100.0,
+            base: 2.0,
+        };
+
+        let unrated = Player::with_config(config);
+        assert_eq!(unrated.rating, 0.0);
+        assert_eq!(unrated.rd, 50.0);
+
+        // Two equally-rated players should still be a 50/50 matchup under
+        // any choice of scale/base.
+        let opponent = Player::with_config(config);
+        assert!((unrated.expected_outcome(&opponent) - 0.5).abs() < 1e-9);
+    }
+
+    /// (12) Mixing `GlickoConfig`s across players in one rating period
+    /// violates the shared-config invariant documented on `Player`
+    #[test]
+    #[should_panic]
+    fn test_update_panics_on_mismatched_config() {
+        let mut player = Player {
+            rating: 1500.0,
+            rd: 200.0,
+            config: GlickoConfig::default(),
+        };
+        let opponent = Player {
+            rating: 1400.0,
+            rd: 30.0,
+            config: GlickoConfig {
+                default_rating: 0.0,
+                default_rd: 50.0,
+                scale: 100.0,
+                base: 2.0,
+            },
+        };
+
+        player.update(&vec![GameResult(opponent, Outcome::Win, None)]);
+    }
 }
 
 fn main() {}